@@ -34,6 +34,9 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 const LEN_MAPS: [&'static str; 29] = [
     "",
     "",
@@ -86,6 +89,85 @@ fn str_binary_search(haystack: &str, needle: &str, len: usize) -> Result<usize,
     Err(start * len)
 }
 
+/// Returns the index of the first word in `LEN_MAPS[len]` whose first byte is `>= target`,
+/// by binary search over just the first byte of each `len`-wide word.
+const fn first_byte_lower_bound(list: &'static str, len: usize, target: u8) -> u16 {
+    let bytes = list.as_bytes();
+    let count = bytes.len() / len;
+    assert!(
+        count <= u16::MAX as usize,
+        "word bucket exceeds u16::MAX words; widen the jump index"
+    );
+    let mut lo = 0usize;
+    let mut hi = count;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if bytes[mid * len] < target {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo as u16
+}
+
+/// For a single length bucket, gives the start index (in words, not bytes) of the sub-slice of
+/// words beginning with each letter `a..=z`; slot 26 is an end sentinel equal to the bucket's
+/// word count.
+const fn first_letter_offsets(list: &'static str, len: usize) -> [u16; 27] {
+    let mut offsets = [0u16; 27];
+    if len == 0 {
+        return offsets;
+    }
+    let mut letter = 0usize;
+    while letter < 26 {
+        offsets[letter] = first_byte_lower_bound(list, len, b'a' + letter as u8);
+        letter += 1;
+    }
+    let count = list.len() / len;
+    assert!(
+        count <= u16::MAX as usize,
+        "word bucket exceeds u16::MAX words; widen the jump index"
+    );
+    offsets[26] = count as u16;
+    offsets
+}
+
+/// Two-level jump index: for each length bucket and each leading letter `a..=z`, the `[lo, hi)`
+/// word range beginning with that letter. Lets [`word_exists`] narrow its binary search to a
+/// single letter's words before comparing a single byte.
+const FIRST_LETTER_INDEX: [[u16; 27]; 29] = [
+    [0; 27],
+    [0; 27],
+    first_letter_offsets(LEN_MAPS[2], 2),
+    first_letter_offsets(LEN_MAPS[3], 3),
+    first_letter_offsets(LEN_MAPS[4], 4),
+    first_letter_offsets(LEN_MAPS[5], 5),
+    first_letter_offsets(LEN_MAPS[6], 6),
+    first_letter_offsets(LEN_MAPS[7], 7),
+    first_letter_offsets(LEN_MAPS[8], 8),
+    first_letter_offsets(LEN_MAPS[9], 9),
+    first_letter_offsets(LEN_MAPS[10], 10),
+    first_letter_offsets(LEN_MAPS[11], 11),
+    first_letter_offsets(LEN_MAPS[12], 12),
+    first_letter_offsets(LEN_MAPS[13], 13),
+    first_letter_offsets(LEN_MAPS[14], 14),
+    first_letter_offsets(LEN_MAPS[15], 15),
+    first_letter_offsets(LEN_MAPS[16], 16),
+    first_letter_offsets(LEN_MAPS[17], 17),
+    first_letter_offsets(LEN_MAPS[18], 18),
+    first_letter_offsets(LEN_MAPS[19], 19),
+    first_letter_offsets(LEN_MAPS[20], 20),
+    first_letter_offsets(LEN_MAPS[21], 21),
+    first_letter_offsets(LEN_MAPS[22], 22),
+    first_letter_offsets(LEN_MAPS[23], 23),
+    first_letter_offsets(LEN_MAPS[24], 24),
+    first_letter_offsets(LEN_MAPS[25], 25),
+    first_letter_offsets(LEN_MAPS[26], 26),
+    first_letter_offsets(LEN_MAPS[27], 27),
+    first_letter_offsets(LEN_MAPS[28], 28),
+];
+
 /// Accepts a lowercase ASCII encoded string reference and returns whether it is a valid word or not.
 /// Note: this will always fail if there are any characters outside of the lowercase range \[a-z\].
 ///
@@ -97,13 +179,19 @@ fn str_binary_search(haystack: &str, needle: &str, len: usize) -> Result<usize,
 /// ```
 pub fn word_exists(word: &str) -> bool {
     let word_len = word.len();
-    if word.len() < 2 {
+    if !(2..=28).contains(&word_len) {
         return false;
     }
-    // Get list of valid words that length
-    let list = *LEN_MAPS.get(word_len).unwrap_or(&"");
-    // Perform binary search on the string list with uniform length
-    str_binary_search(list, word, word_len).is_ok()
+    let first = word.as_bytes()[0];
+    if !first.is_ascii_lowercase() {
+        return false;
+    }
+    // Narrow the search to just the words starting with this letter before comparing full strings
+    let letter = (first - b'a') as usize;
+    let offsets = &FIRST_LETTER_INDEX[word_len];
+    let lo = offsets[letter] as usize * word_len;
+    let hi = offsets[letter + 1] as usize * word_len;
+    str_binary_search(&LEN_MAPS[word_len][lo..hi], word, word_len).is_ok()
 }
 
 /// Returns an iterator of the valid words in the range [begin, end).
@@ -192,11 +280,393 @@ pub fn word_iterator_by_len(len: usize) -> impl Iterator<Item = &'static str> {
     })
 }
 
+fn is_ascii_lowercase_str(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+/// Finds the lower-bound index of `prefix` within `LEN_MAPS[len]`, i.e. the start of the first
+/// word in that bucket which is `>= prefix`. `prefix.len()` must be `<= len`.
+fn prefix_lower_bound(len: usize, prefix: &str) -> usize {
+    let list = LEN_MAPS[len];
+    if list.is_empty() {
+        return 0;
+    }
+    let mut buf = [0u8; 28];
+    buf[..prefix.len()].copy_from_slice(prefix.as_bytes());
+    let needle = core::str::from_utf8(&buf[..len]).unwrap();
+    match str_binary_search(list, needle, len) {
+        Ok(pos) => pos,
+        Err(pos) => pos,
+    }
+}
+
+/// Returns an iterator of every valid word starting with the given prefix. Not guaranteed to
+/// return them in any particular order other than grouped by length.
+///
+/// Any prefix containing a character outside of `[a-z]`, or longer than 28 characters, yields an
+/// empty iterator.
+///
+/// Example:
+/// ```rust
+/// use wordnik_list::word_prefix_iterator;
+/// // Every word starting with "rust"
+/// let vec: Vec<&str> = word_prefix_iterator("rust").collect();
+/// assert!(vec.contains(&"rusty"));
+/// ```
+pub fn word_prefix_iterator(prefix: &str) -> impl Iterator<Item = &'static str> {
+    let valid = prefix.len() <= 28 && is_ascii_lowercase_str(prefix);
+    let prefix_len = prefix.len();
+    let mut buf = [0u8; 28];
+    if valid {
+        buf[..prefix_len].copy_from_slice(prefix.as_bytes());
+    }
+    prefix_scan(buf, prefix_len, valid)
+}
+
+/// Shared bucket-walk behind [`word_prefix_iterator`] and [`word_prefix_iterator_folded`]: yields
+/// every valid word starting with the first `prefix_len` bytes of `buf`, or nothing if `valid`
+/// is false.
+fn prefix_scan(
+    buf: [u8; 28],
+    prefix_len: usize,
+    valid: bool,
+) -> impl Iterator<Item = &'static str> {
+    let mut len = if valid { prefix_len.max(2) } else { 29 };
+    let mut index = if valid {
+        prefix_lower_bound(len, core::str::from_utf8(&buf[..prefix_len]).unwrap())
+    } else {
+        0
+    };
+    core::iter::from_fn(move || loop {
+        if len > 28 {
+            return None;
+        }
+        let prefix = core::str::from_utf8(&buf[..prefix_len]).unwrap();
+        if index + len > LEN_MAPS[len].len() {
+            len += 1;
+            if len > 28 {
+                return None;
+            }
+            index = prefix_lower_bound(len, prefix);
+            continue;
+        }
+        let word = &LEN_MAPS[len][index..index + len];
+        if &word[..prefix_len] != prefix {
+            len += 1;
+            if len > 28 {
+                return None;
+            }
+            index = prefix_lower_bound(len, prefix);
+            continue;
+        }
+        index += len;
+        return Some(word);
+    })
+}
+
+/// Bit set in [`completion_mask`]'s return value when `prefix` is itself a valid word.
+pub const PREFIX_IS_WORD_BIT: u32 = 1 << 26;
+
+/// Returns a bitset where bit `i` is set iff there is a valid word of the form
+/// `prefix + ('a'+i) + ...`, i.e. which letters can legally follow `prefix`. Bit
+/// [`PREFIX_IS_WORD_BIT`] is set if `prefix` is itself a valid word.
+///
+/// Any prefix containing a character outside of `[a-z]`, or longer than 28 characters, yields 0.
+///
+/// Example:
+/// ```rust
+/// use wordnik_list::completion_mask;
+/// // "rust" can be followed by 'y' (as in "rusty")
+/// let mask = completion_mask("rust");
+/// assert_ne!(mask & (1 << (b'y' - b'a')), 0);
+/// ```
+pub fn completion_mask(prefix: &str) -> u32 {
+    if prefix.len() > 28 || !is_ascii_lowercase_str(prefix) {
+        return 0;
+    }
+    let mut mask = if word_exists(prefix) {
+        PREFIX_IS_WORD_BIT
+    } else {
+        0
+    };
+    for len in (prefix.len() + 1)..=28 {
+        let list = LEN_MAPS[len];
+        if list.is_empty() {
+            continue;
+        }
+        let mut index = prefix_lower_bound(len, prefix);
+        while index + len <= list.len() {
+            let word = &list[index..index + len];
+            if &word[..prefix.len()] != prefix {
+                break;
+            }
+            mask |= 1 << (word.as_bytes()[prefix.len()] - b'a');
+            index += len;
+        }
+    }
+    mask
+}
+
+/// Computes the Levenshtein distance between `query` and `candidate`, or `None` if it exceeds
+/// `max_distance`. Uses a single rolling row of `u8` costs (words in this crate are at most 28
+/// bytes, so costs never exceed 28), abandoning early once every cost in the row exceeds
+/// `max_distance` (safe because the minimum of a row never decreases in later rows). Returns `None` if
+/// `query` is longer than 28 bytes, since no valid word is.
+#[cfg(feature = "alloc")]
+fn bounded_edit_distance(query: &str, candidate: &str, max_distance: usize) -> Option<usize> {
+    let n = query.len();
+    if n > 28 {
+        return None;
+    }
+    let mut row = [0u8; 29];
+    for (i, cost) in row.iter_mut().enumerate().take(n + 1) {
+        *cost = i as u8;
+    }
+    for (ci, cb) in candidate.bytes().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = (ci + 1) as u8;
+        let mut row_min = row[0];
+        for (j, qb) in query.bytes().enumerate() {
+            let cost: u8 = if qb == cb { 0 } else { 1 };
+            let deletion = row[j + 1] + 1;
+            let insertion = row[j] + 1;
+            let substitution = prev_diag + cost;
+            prev_diag = row[j + 1];
+            row[j + 1] = deletion.min(insertion).min(substitution);
+            row_min = row_min.min(row[j + 1]);
+        }
+        if row_min as usize > max_distance {
+            return None;
+        }
+    }
+    let distance = row[n] as usize;
+    if distance <= max_distance {
+        Some(distance)
+    } else {
+        None
+    }
+}
+
+/// Returns valid words within `max_distance` Levenshtein edits of `word`, sorted by ascending
+/// distance and then alphabetically, useful for spell-correction on top of [`word_exists`].
+///
+/// Only buckets whose length is within `max_distance` of `word.len()` can contain candidates,
+/// since a word differing in length by more than `max_distance` cannot be within that distance.
+///
+/// Example:
+/// ```rust
+/// use wordnik_list::suggest;
+/// // "rusty" misspelled with a transposed letter
+/// let vec: Vec<&str> = suggest("rsuty", 2).collect();
+/// assert!(vec.contains(&"rusty"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn suggest(word: &str, max_distance: usize) -> impl Iterator<Item = &'static str> {
+    let min_len = word.len().saturating_sub(max_distance).max(2);
+    let max_len = word.len().saturating_add(max_distance).min(28);
+
+    let mut matches: alloc::vec::Vec<(usize, &'static str)> = alloc::vec::Vec::new();
+    if min_len <= max_len {
+        #[allow(clippy::needless_range_loop)]
+        for len in min_len..=max_len {
+            let list = LEN_MAPS[len];
+            if list.is_empty() {
+                continue;
+            }
+            for candidate in list.as_bytes().chunks_exact(len) {
+                let candidate = core::str::from_utf8(candidate).unwrap();
+                if let Some(distance) = bounded_edit_distance(word, candidate, max_distance) {
+                    matches.push((distance, candidate));
+                }
+            }
+        }
+    }
+    matches.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+    matches.into_iter().map(|(_, word)| word)
+}
+
+/// Folds a single `char` down to its base ASCII lowercase letter, or `None` if it has no
+/// reasonable `[a-z]` equivalent. Covers ASCII case-folding plus the common accented Latin-1 /
+/// Latin-Extended letters.
+fn fold_char(c: char) -> Option<u8> {
+    Some(match c {
+        'a'..='z' => c as u8,
+        'A'..='Z' => c as u8 + (b'a' - b'A'),
+        'à' | 'â' | 'ä' | 'á' | 'ã' | 'å' | 'À' | 'Â' | 'Ä' | 'Á' | 'Ã' | 'Å' => b'a',
+        'é' | 'è' | 'ê' | 'ë' | 'É' | 'È' | 'Ê' | 'Ë' => b'e',
+        'í' | 'ì' | 'î' | 'ï' | 'Í' | 'Ì' | 'Î' | 'Ï' => b'i',
+        'ö' | 'ó' | 'ô' | 'ò' | 'õ' | 'Ö' | 'Ó' | 'Ô' | 'Ò' | 'Õ' => b'o',
+        'ü' | 'ú' | 'û' | 'ù' | 'Ü' | 'Ú' | 'Û' | 'Ù' => b'u',
+        'ÿ' | 'Ÿ' | 'ý' | 'Ý' => b'y',
+        'ç' | 'Ç' => b'c',
+        'ñ' | 'Ñ' => b'n',
+        _ => return None,
+    })
+}
+
+/// Folds `word` into `buf`, returning the number of bytes written, or `None` if `word` is longer
+/// than `buf` once folded or contains a character with no `[a-z]` equivalent.
+fn fold_into_buf(word: &str, buf: &mut [u8; 28]) -> Option<usize> {
+    let mut len = 0;
+    for c in word.chars() {
+        if len >= buf.len() {
+            return None;
+        }
+        buf[len] = fold_char(c)?;
+        len += 1;
+    }
+    Some(len)
+}
+
+/// Case-insensitive, diacritic-folding variant of [`word_exists`]. ASCII letters are
+/// case-folded and common accented Latin letters (e.g. `é`, `ñ`, `ö`) are folded to their base
+/// letter before the lookup, so `"Rusty"`, `"RUSTY"`, and `"rüsty"` are all treated as `"rusty"`.
+///
+/// Example:
+/// ```rust
+/// use wordnik_list::word_exists_folded;
+/// assert!(word_exists_folded("Rusty"));
+/// assert!(word_exists_folded("RUSTY"));
+/// ```
+pub fn word_exists_folded(word: &str) -> bool {
+    let mut buf = [0u8; 28];
+    match fold_into_buf(word, &mut buf) {
+        Some(len) => word_exists(core::str::from_utf8(&buf[..len]).unwrap()),
+        None => false,
+    }
+}
+
+/// Case-insensitive, diacritic-folding variant of [`word_prefix_iterator`].
+///
+/// Example:
+/// ```rust
+/// use wordnik_list::word_prefix_iterator_folded;
+/// let vec: Vec<&str> = word_prefix_iterator_folded("RUST").collect();
+/// assert!(vec.contains(&"rusty"));
+/// ```
+pub fn word_prefix_iterator_folded(prefix: &str) -> impl Iterator<Item = &'static str> {
+    let mut buf = [0u8; 28];
+    let folded_len = fold_into_buf(prefix, &mut buf);
+    let valid = folded_len.is_some();
+    let prefix_len = folded_len.unwrap_or(0);
+    prefix_scan(buf, prefix_len, valid)
+}
+
+/// Longest pattern (in bytes) that [`word_pattern`] accepts; it is copied into a buffer of this
+/// size, and a longer pattern yields an empty iterator even if it is mostly `*`.
+const MAX_PATTERN_LEN: usize = 64;
+
+fn is_pattern_byte(b: u8) -> bool {
+    b.is_ascii_lowercase() || b == b'?' || b == b'*'
+}
+
+/// Greedy two-pointer glob match of `pattern` (`?` = one letter, `*` = any run of letters)
+/// against `word`, backtracking to the most recent `*` on mismatch.
+fn glob_match(pattern: &[u8], word: &[u8]) -> bool {
+    let (mut pi, mut wi) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while wi < word.len() {
+        if pi < pattern.len() && (pattern[pi] == b'?' || pattern[pi] == word[wi]) {
+            pi += 1;
+            wi += 1;
+        } else if pi < pattern.len() && pattern[pi] == b'*' {
+            star = Some((pi, wi));
+            pi += 1;
+        } else if let Some((star_pi, star_wi)) = star {
+            pi = star_pi + 1;
+            wi = star_wi + 1;
+            star = Some((star_pi, wi));
+        } else {
+            return false;
+        }
+    }
+    while pattern.get(pi) == Some(&b'*') {
+        pi += 1;
+    }
+    pi == pattern.len()
+}
+
+/// Returns an iterator of every valid word matching `pattern`, where `?` matches exactly one
+/// `[a-z]` letter and `*` matches any run of letters (including none). This supports crossword
+/// and Scrabble-rack style queries like `"r?st?"` or `"ab*n"`.
+///
+/// The pattern's literal prefix (the run of plain letters before the first `?`/`*`) is used to
+/// narrow the bucket scan with a lower-bound search before the glob match runs. A pattern
+/// containing a byte outside of `[a-z?*]`, or longer than 64 bytes, yields an empty iterator. A
+/// pattern made entirely of `*` degenerates to [`word_iterator`].
+///
+/// Example:
+/// ```rust
+/// use wordnik_list::word_pattern;
+/// let vec: Vec<&str> = word_pattern("r?st?").collect();
+/// assert!(vec.contains(&"rusty"));
+/// ```
+pub fn word_pattern(pattern: &str) -> impl Iterator<Item = &'static str> {
+    let pb = pattern.as_bytes();
+    let valid = pb.len() <= MAX_PATTERN_LEN && pb.iter().all(|&b| is_pattern_byte(b));
+
+    let mut buf = [0u8; MAX_PATTERN_LEN];
+    let plen = if valid { pb.len() } else { 0 };
+    if valid {
+        buf[..plen].copy_from_slice(pb);
+    }
+
+    let has_star = buf[..plen].contains(&b'*');
+    let fixed_count = buf[..plen].iter().filter(|&&b| b != b'*').count();
+    let lit_end = buf[..plen]
+        .iter()
+        .position(|&b| b == b'?' || b == b'*')
+        .unwrap_or(plen);
+
+    let mut len = if !valid {
+        29
+    } else if has_star {
+        fixed_count.max(1)
+    } else {
+        // No length-0 words exist, so an empty (non-star) pattern must floor to a real bucket
+        // just to hit the exhausted check below and end the iteration instead of looping forever.
+        plen.max(1)
+    };
+    let mut index = if valid && len <= 28 {
+        prefix_lower_bound(len, core::str::from_utf8(&buf[..lit_end]).unwrap())
+    } else {
+        0
+    };
+
+    core::iter::from_fn(move || loop {
+        if !valid || len > 28 {
+            return None;
+        }
+        let literal = core::str::from_utf8(&buf[..lit_end]).unwrap();
+        if index + len > LEN_MAPS[len].len() || &LEN_MAPS[len][index..index + lit_end] != literal
+        {
+            if !has_star {
+                return None;
+            }
+            len += 1;
+            if len > 28 {
+                return None;
+            }
+            index = prefix_lower_bound(len, literal);
+            continue;
+        }
+        let word = &LEN_MAPS[len][index..index + len];
+        index += len;
+        if glob_match(&buf[..plen], word.as_bytes()) {
+            return Some(word);
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(test)]
     extern crate std;
-    use crate::{str_binary_search, word_exists, word_iterator, word_iterator_by_len, word_range};
+    use crate::{
+        completion_mask, str_binary_search, word_exists, word_exists_folded, word_iterator,
+        word_iterator_by_len, word_pattern, word_prefix_iterator, word_prefix_iterator_folded,
+        word_range, PREFIX_IS_WORD_BIT,
+    };
 
     #[test]
     fn test_size() {
@@ -245,12 +715,121 @@ mod tests {
     #[test]
     fn randomized_reading() {
         let word_vec: std::vec::Vec<&'static str> = word_iterator().collect();
+
+        // Before: full-bucket binary search, ignoring the first-letter jump index
+        let now = std::time::Instant::now();
+        for i in 0..100000 {
+            let word = word_vec[(i * 80) % word_vec.len()];
+            assert!(str_binary_search(crate::LEN_MAPS[word.len()], word, word.len()).is_ok());
+        }
+        let full_scan = now.elapsed().div_f32(100000.0);
+
+        // After: word_exists, narrowed by the first-letter jump index
         let now = std::time::Instant::now();
         for i in 0..100000 {
             assert!(word_exists(word_vec[(i * 80) % word_vec.len()]));
         }
-        std::println!("Random iteration lookup: {:?}", now.elapsed().div_f32(100000.0));
+        let jump_indexed = now.elapsed().div_f32(100000.0);
+
+        std::println!("Random iteration lookup, full bucket scan: {:?}", full_scan);
+        std::println!("Random iteration lookup, first-letter index: {:?}", jump_indexed);
     }
+    #[test]
+    fn test_word_prefix_iterator() {
+        let vec: std::vec::Vec<&str> = word_prefix_iterator("rust").collect();
+        assert!(vec.contains(&"rust"));
+        assert!(vec.contains(&"rusty"));
+        assert!(!vec.iter().any(|word| !word.starts_with("rust")));
+
+        assert_eq!(word_prefix_iterator("").count(), word_iterator().count());
+        assert_eq!(word_prefix_iterator("zzzzzz").count(), 0);
+        assert_eq!(word_prefix_iterator("r1st").count(), 0);
+    }
+
+    #[test]
+    fn test_completion_mask() {
+        // "rust" is itself a word, and "rusty" means "rust" can be followed by 'y'
+        let mask = completion_mask("rust");
+        assert_ne!(mask & PREFIX_IS_WORD_BIT, 0);
+        assert_ne!(mask & (1 << (b'y' - b'a')), 0);
+        assert_eq!(completion_mask("zzzzzz"), 0);
+        assert_eq!(completion_mask("r1st"), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_suggest() {
+        use crate::suggest;
+        let vec: std::vec::Vec<&str> = suggest("rsuty", 2).collect();
+        assert!(vec.contains(&"rusty"));
+        // With a distance of 0, only the word itself is suggested
+        let exact: std::vec::Vec<&str> = suggest("rusty", 0).collect();
+        assert_eq!(exact, std::vec!["rusty"]);
+        assert_eq!(suggest("zzzzzzzzzzzzzzzzzzzzzzzzzzzz", 0).count(), 0);
+        // max_distance near usize::MAX must not overflow when added to word.len()
+        assert!(suggest("rust", usize::MAX).any(|w| w == "rusty"));
+        // A query longer than any valid word still terminates instead of overflowing the row
+        let long_query: std::string::String = "a".repeat(40);
+        assert_eq!(suggest(&long_query, usize::MAX).count(), 0);
+    }
+
+    #[test]
+    fn test_word_exists_folded() {
+        assert!(word_exists_folded("rusty"));
+        assert!(word_exists_folded("Rusty"));
+        assert!(word_exists_folded("RUSTY"));
+        assert!(word_exists_folded("rüsty"));
+        assert!(!word_exists_folded("rustying"));
+        assert!(!word_exists_folded("1ab"));
+    }
+
+    #[test]
+    fn test_word_prefix_iterator_folded() {
+        let vec: std::vec::Vec<&str> = word_prefix_iterator_folded("RUST").collect();
+        assert!(vec.contains(&"rusty"));
+        assert_eq!(word_prefix_iterator_folded("1ab").count(), 0);
+    }
+
+    #[test]
+    fn test_word_pattern() {
+        let vec: std::vec::Vec<&str> = word_pattern("r?st?").collect();
+        assert!(vec.contains(&"rusty"));
+        assert!(!vec.iter().any(|word| word.len() != 5));
+
+        let vec: std::vec::Vec<&str> = word_pattern("ru*n").collect();
+        assert!(vec.contains(&"ruin"));
+
+        // An all-`*` pattern degenerates to `word_iterator`
+        assert_eq!(word_pattern("*").count(), word_iterator().count());
+        // An all-`?` fixed-length pattern yields the whole bucket for that length
+        assert_eq!(
+            word_pattern("???").count(),
+            word_iterator_by_len(3).count()
+        );
+
+        assert_eq!(word_pattern("r1st").count(), 0);
+        // An empty pattern can't match any word (there are no length-0 words) and must terminate
+        assert_eq!(word_pattern("").count(), 0);
+    }
+
+    #[test]
+    fn test_first_letter_index() {
+        // Every word in each bucket falls within its letter's [lo, hi) range
+        for (len, offsets) in crate::FIRST_LETTER_INDEX.iter().enumerate().skip(2) {
+            let list = crate::LEN_MAPS[len];
+            if list.is_empty() {
+                continue;
+            }
+            for letter in 0..26 {
+                let lo = offsets[letter] as usize * len;
+                let hi = offsets[letter + 1] as usize * len;
+                for word in list.as_bytes()[lo..hi].chunks_exact(len) {
+                    assert_eq!(word[0], b'a' + letter as u8);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_str_binary_search() {
         let haystack = "bcef";